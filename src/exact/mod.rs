@@ -0,0 +1,355 @@
+//! Exact solvers for small instances, providing ground-truth optima to validate the heuristics.
+//!
+//! [`solve`] returns a provably optimal `Tour` for small `n`. It uses branch-and-bound over the
+//! `distance_matrix`, pruning partial routes with a lower bound built from the two cheapest
+//! incident edges of each unvisited city, and falls back to Held–Karp dynamic programming for the
+//! smallest instances, where its `O(2ⁿ · n²)` running time is the quicker choice.
+//!
+//! The optimised objective is the same one every other solver minimises — the total length of the
+//! route as measured by [`get_route_distance`](super::get_route_distance).
+//!
+//!# Examples
+//!
+//!```
+//!extern crate travelling_salesman;
+//!
+//!fn main() {
+//!  let tour = travelling_salesman::exact::solve(&[
+//!     (27.0, 78.0),
+//!     (18.0, 24.0),
+//!     (48.0, 62.0),
+//!     (83.0, 77.0),
+//!     (55.0, 56.0),
+//!  ]);
+//!
+//!  println!("Optimal distance: {}, route: {:?}", tour.distance, tour.route);
+//!}
+//!```
+use super::{get_distance_matrix, get_route_distance, Tour};
+
+/// The largest `n` for which Held–Karp is preferred over branch-and-bound.
+const HELD_KARP_LIMIT: usize = 15;
+
+/// Returns the provably optimal `Tour` for the given `cities`.
+///
+/// Intended for small `n` (up to roughly 15–20 cities); beyond that the exact search becomes
+/// impractical and one of the heuristic solvers should be used instead.
+pub fn solve(cities: &[(f64, f64)]) -> Tour {
+    solve_matrix(&get_distance_matrix(cities))
+}
+
+/// Returns the provably optimal `Tour` for a precomputed `distance_matrix`.
+pub fn solve_matrix(distance_matrix: &Vec<Vec<f64>>) -> Tour {
+    let len = distance_matrix.len();
+
+    let route = if len <= 1 {
+        (0..len).collect()
+    } else if len <= HELD_KARP_LIMIT {
+        held_karp(distance_matrix)
+    } else {
+        branch_and_bound(distance_matrix)
+    };
+
+    Tour {
+        distance: get_route_distance(distance_matrix, &route),
+        route,
+    }
+}
+
+/// Solves the instance exactly with Held–Karp dynamic programming.
+///
+/// `dp[mask][j]` holds the length of the cheapest path that visits exactly the cities in `mask` and
+/// ends at `j`. Every city is allowed as a start point, so the result is the shortest Hamiltonian
+/// path with free endpoints — the open-route objective the rest of the crate minimises.
+fn held_karp(distance_matrix: &Vec<Vec<f64>>) -> Vec<usize> {
+    let len = distance_matrix.len();
+    let full = (1usize << len) - 1;
+    let index = |mask: usize, end: usize| mask * len + end;
+
+    let mut cost = vec![f64::INFINITY; (1 << len) * len];
+    let mut previous = vec![usize::MAX; (1 << len) * len];
+
+    for start in 0..len {
+        cost[index(1 << start, start)] = 0.0;
+    }
+
+    for mask in 1..=full {
+        for end in 0..len {
+            if mask & (1 << end) == 0 {
+                continue;
+            }
+
+            let here = cost[index(mask, end)];
+            if here.is_infinite() {
+                continue;
+            }
+
+            for next in 0..len {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+
+                let next_mask = mask | (1 << next);
+                let candidate = here + distance_matrix[end][next];
+
+                if candidate < cost[index(next_mask, next)] {
+                    cost[index(next_mask, next)] = candidate;
+                    previous[index(next_mask, next)] = end;
+                }
+            }
+        }
+    }
+
+    let mut end = 0;
+    let mut best = f64::INFINITY;
+    for candidate in 0..len {
+        if cost[index(full, candidate)] < best {
+            best = cost[index(full, candidate)];
+            end = candidate;
+        }
+    }
+
+    let mut route = Vec::with_capacity(len);
+    let mut mask = full;
+    let mut city = end;
+    while city != usize::MAX {
+        route.push(city);
+        let parent = previous[index(mask, city)];
+        mask &= !(1 << city);
+        city = parent;
+    }
+    route.reverse();
+
+    route
+}
+
+/// Solves the instance exactly with depth-first branch-and-bound.
+///
+/// Partial routes are pruned whenever their cost plus a lower bound on completing the tour already
+/// exceeds the best complete route found so far.
+fn branch_and_bound(distance_matrix: &Vec<Vec<f64>>) -> Vec<usize> {
+    let len = distance_matrix.len();
+    let cheapest_pair = two_cheapest_incident_edges(distance_matrix);
+
+    let mut visited = vec![false; len];
+    let mut route = Vec::with_capacity(len);
+    let mut best_route = (0..len).collect();
+    let mut best_distance = f64::INFINITY;
+
+    for start in 0..len {
+        visited[start] = true;
+        route.push(start);
+
+        extend(
+            distance_matrix,
+            &cheapest_pair,
+            &mut visited,
+            &mut route,
+            0.0,
+            &mut best_route,
+            &mut best_distance,
+        );
+
+        route.pop();
+        visited[start] = false;
+    }
+
+    best_route
+}
+
+/// Recursively extends `route`, pruning with the two-cheapest-edges lower bound.
+#[allow(clippy::too_many_arguments)]
+fn extend(
+    distance_matrix: &Vec<Vec<f64>>,
+    cheapest_pair: &[(f64, f64)],
+    visited: &mut [bool],
+    route: &mut Vec<usize>,
+    distance: f64,
+    best_route: &mut Vec<usize>,
+    best_distance: &mut f64,
+) {
+    let len = distance_matrix.len();
+
+    if route.len() == len {
+        if distance < *best_distance {
+            *best_distance = distance;
+            *best_route = route.clone();
+        }
+
+        return;
+    }
+
+    if distance + lower_bound(cheapest_pair, visited) >= *best_distance {
+        return;
+    }
+
+    let current = *route.last().unwrap();
+
+    for next in 0..len {
+        if visited[next] {
+            continue;
+        }
+
+        visited[next] = true;
+        route.push(next);
+
+        extend(
+            distance_matrix,
+            cheapest_pair,
+            visited,
+            route,
+            distance + distance_matrix[current][next],
+            best_route,
+            best_distance,
+        );
+
+        route.pop();
+        visited[next] = false;
+    }
+}
+
+/// A lower bound on the cost of completing the route through the still-unvisited cities.
+///
+/// The remaining work is an open path `current → u₁ → … → u_k` over the unvisited cities. Every
+/// edge of it is incident to at least one unvisited city, so summing each unvisited city's two
+/// cheapest incident edges and halving gives the classic degree-2 relaxation. But in an *open*
+/// path the final city `u_k` has degree 1, so charging it a second edge overcounts by that edge's
+/// length; since `u_k` is unknown we subtract the largest possible over-charge, `max(c2) / 2`,
+/// which keeps the bound admissible (never above the true remaining cost). The edge from `current`
+/// into the unvisited set only tightens the true cost further, so dropping it stays conservative.
+///
+/// Concretely: `2·R = Σ_unvisited A(v) + len(current, u₁)` where `A(v)` is `v`'s incident length
+/// in the remaining path; degree-2 cities satisfy `A(v) ≥ c1+c2` and `u_k` satisfies `A(u_k) ≥ c1`,
+/// so `2·R ≥ Σ(c1+c2) − c2(u_k) ≥ Σ(c1+c2) − max(c2)`, i.e. `R ≥ bound`.
+fn lower_bound(cheapest_pair: &[(f64, f64)], visited: &[bool]) -> f64 {
+    let mut sum = 0.0;
+    let mut max_second: f64 = 0.0;
+
+    for (city, &seen) in visited.iter().enumerate() {
+        if seen {
+            continue;
+        }
+
+        let (c1, c2) = cheapest_pair[city];
+        sum += (c1 + c2) / 2.0;
+        max_second = max_second.max(c2);
+    }
+
+    sum - max_second / 2.0
+}
+
+/// Returns, for each city, the lengths of its two cheapest incident edges as `(cheapest, second)`.
+fn two_cheapest_incident_edges(distance_matrix: &Vec<Vec<f64>>) -> Vec<(f64, f64)> {
+    let len = distance_matrix.len();
+
+    (0..len)
+        .map(|city| {
+            let mut edges: Vec<f64> = (0..len)
+                .filter(|&other| other != city)
+                .map(|other| distance_matrix[city][other])
+                .collect();
+
+            edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            (edges[0], edges[1])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the optimal open-path length by exhaustively scoring every permutation.
+    fn brute_force(distance_matrix: &Vec<Vec<f64>>) -> f64 {
+        let len = distance_matrix.len();
+        let mut route: Vec<usize> = (0..len).collect();
+        let mut best = f64::INFINITY;
+
+        permute(&mut route, 0, &mut |order| {
+            best = best.min(get_route_distance(distance_matrix, order));
+        });
+
+        best
+    }
+
+    /// Returns the optimal open-path length among routes that begin at `start`.
+    fn brute_force_from(distance_matrix: &Vec<Vec<f64>>, start: usize) -> f64 {
+        let len = distance_matrix.len();
+        let mut rest: Vec<usize> = (0..len).filter(|&c| c != start).collect();
+        let mut best = f64::INFINITY;
+
+        permute(&mut rest, 0, &mut |order| {
+            let mut route = vec![start];
+            route.extend_from_slice(order);
+            best = best.min(get_route_distance(distance_matrix, &route));
+        });
+
+        best
+    }
+
+    /// Invokes `visit` on every permutation of `route`, by recursive swap-and-restore backtracking.
+    fn permute(route: &mut Vec<usize>, k: usize, visit: &mut dyn FnMut(&[usize])) {
+        if k + 1 >= route.len() {
+            visit(route);
+            return;
+        }
+
+        for i in k..route.len() {
+            route.swap(k, i);
+            permute(route, k + 1, visit);
+            route.swap(k, i);
+        }
+    }
+
+    /// A symmetric but non-metric matrix (the triangle inequality is violated) that also exercises
+    /// the branch-and-bound pruning lower bound, rather than the easy Euclidean case.
+    fn non_metric_matrix() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.0, 29.0, 120.0, 46.0, 68.0, 52.0, 72.0],
+            vec![29.0, 0.0, 55.0, 46.0, 42.0, 43.0, 43.0],
+            vec![120.0, 55.0, 0.0, 68.0, 46.0, 55.0, 23.0],
+            vec![46.0, 46.0, 68.0, 0.0, 82.0, 15.0, 72.0],
+            vec![68.0, 42.0, 46.0, 82.0, 0.0, 74.0, 23.0],
+            vec![52.0, 43.0, 55.0, 15.0, 74.0, 0.0, 61.0],
+            vec![72.0, 43.0, 23.0, 72.0, 23.0, 61.0, 0.0],
+        ]
+    }
+
+    #[test]
+    fn branch_and_bound_matches_ground_truth_on_non_metric_matrix() {
+        let matrix = non_metric_matrix();
+        let optimum = brute_force(&matrix);
+
+        let bnb = get_route_distance(&matrix, &branch_and_bound(&matrix));
+        let hk = get_route_distance(&matrix, &held_karp(&matrix));
+
+        assert!(
+            (bnb - optimum).abs() < 1e-9,
+            "branch-and-bound {bnb} should equal optimum {optimum}"
+        );
+        assert!(
+            (hk - optimum).abs() < 1e-9,
+            "held–karp {hk} should equal optimum {optimum}"
+        );
+    }
+
+    #[test]
+    fn lower_bound_never_exceeds_true_remaining_cost() {
+        // With only the start city visited, the bound on the remaining open path must not exceed
+        // the true optimal completion cost, or branch-and-bound could prune the optimum.
+        let matrix = non_metric_matrix();
+        let cheapest_pair = two_cheapest_incident_edges(&matrix);
+
+        for start in 0..matrix.len() {
+            let mut visited = vec![false; matrix.len()];
+            visited[start] = true;
+
+            let bound = lower_bound(&cheapest_pair, &visited);
+            let optimal_completion = brute_force_from(&matrix, start);
+            assert!(
+                bound <= optimal_completion + 1e-9,
+                "bound {bound} from start {start} exceeds optimal completion {optimal_completion}"
+            );
+        }
+    }
+}
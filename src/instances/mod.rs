@@ -0,0 +1,172 @@
+//! Bundled standard TSPLIB benchmark instances with their known optimal tour lengths.
+//!
+//! Each constructor returns a [`BenchmarkInstance`] pairing a parsed
+//! [`tsplib::Problem`](super::tsplib::Problem) with the published optimum, so a solver's output can
+//! be scored as an approximation ratio against ground truth.
+//!
+//!# Examples
+//!
+//!```
+//!extern crate time;
+//!extern crate travelling_salesman;
+//!
+//!fn main() {
+//!  let att48 = travelling_salesman::instances::att48();
+//!
+//!  let tour = travelling_salesman::simulated_annealing::solve_matrix(
+//!    &att48.problem.distance_matrix(),
+//!    time::Duration::seconds(1),
+//!  );
+//!
+//!  println!("within {:.2}x of optimal", att48.approximation_ratio(&tour));
+//!}
+//!```
+use super::tsplib::{self, Problem};
+use super::Tour;
+
+/// A benchmark problem together with its known optimal tour length.
+///
+/// `optimal_length` is the published *closed-tour* optimum (the cycle that returns to the
+/// starting city). Solvers in this crate minimize the *open-path* `distance`, so scoring must
+/// close the tour first — see [`approximation_ratio`](BenchmarkInstance::approximation_ratio).
+pub struct BenchmarkInstance {
+    pub problem: Problem,
+    pub optimal_length: f64,
+    /// The problem's distance matrix, cached so scoring does not rebuild it on every call.
+    distance_matrix: Vec<Vec<f64>>,
+}
+
+impl BenchmarkInstance {
+    /// Builds an instance from a `problem` and its known closed-tour `optimal_length`, caching the
+    /// distance matrix for scoring.
+    pub fn new(problem: Problem, optimal_length: f64) -> BenchmarkInstance {
+        let distance_matrix = problem.distance_matrix();
+
+        BenchmarkInstance {
+            problem,
+            optimal_length,
+            distance_matrix,
+        }
+    }
+
+    /// Returns the ratio of `tour`'s closed-tour length to the optimum — `1.0` means optimal,
+    /// `1.1` means 10% longer than optimal.
+    ///
+    /// Because `optimal_length` is the closed-tour optimum, the return edge back to the first
+    /// city is added to `tour`'s open-path `distance` before dividing, so the comparison is
+    /// commensurable and never reports a ratio below `1.0`.
+    pub fn approximation_ratio(&self, tour: &Tour) -> f64 {
+        self.closed_tour_length(tour) / self.optimal_length
+    }
+
+    /// Returns `tour`'s length as a closed cycle: its open-path `distance` plus the edge from the
+    /// last city back to the first.
+    pub fn closed_tour_length(&self, tour: &Tour) -> f64 {
+        match (tour.route.first(), tour.route.last()) {
+            (Some(&first), Some(&last)) => tour.distance + self.distance_matrix[last][first],
+            _ => tour.distance,
+        }
+    }
+}
+
+/// The 48-city `ATT` instance, whose optimal closed-tour length is `10628`.
+pub fn att48() -> BenchmarkInstance {
+    let problem = tsplib::parse(ATT48).expect("bundled att48 instance is valid TSPLIB");
+
+    BenchmarkInstance::new(problem, 10628.0)
+}
+
+const ATT48: &str = "\
+NAME: att48
+TYPE: TSP
+COMMENT: 48 capitals of the US (Padberg/Rinaldi)
+DIMENSION: 48
+EDGE_WEIGHT_TYPE: ATT
+NODE_COORD_SECTION
+1 6734 1453
+2 2233 10
+3 5530 1424
+4 401 841
+5 3082 1644
+6 7608 4458
+7 7573 3716
+8 7265 1268
+9 6898 1885
+10 1112 2049
+11 5468 2606
+12 5989 2873
+13 4706 2674
+14 4612 2035
+15 6347 2683
+16 6107 669
+17 7611 5184
+18 7462 3590
+19 7732 4723
+20 5900 3561
+21 4483 3369
+22 6101 1110
+23 5199 2182
+24 1633 2809
+25 4307 2322
+26 675 1006
+27 7555 4819
+28 7541 3981
+29 3177 756
+30 7352 4506
+31 7545 2801
+32 3245 3305
+33 6426 3173
+34 4608 1198
+35 23 2216
+36 7248 3779
+37 7762 4595
+38 7392 2244
+39 3484 2829
+40 6271 2135
+41 4985 140
+42 1916 1569
+43 7280 4899
+44 7509 3239
+45 10 2676
+46 6807 2993
+47 5185 3258
+48 3023 1942
+EOF
+";
+
+#[cfg(test)]
+mod tests {
+    use super::super::get_route_distance;
+    use super::tsplib::{EdgeWeightType, Problem};
+    use super::*;
+
+    #[test]
+    fn att48_parses_to_48_coordinates_with_its_published_optimum() {
+        let instance = att48();
+
+        assert_eq!(instance.problem.coordinates.len(), 48);
+        assert_eq!(instance.optimal_length, 10628.0);
+    }
+
+    #[test]
+    fn approximation_ratio_is_one_for_a_known_optimal_tour() {
+        // A unit square: the optimal closed tour is its perimeter, 4.0, achieved by visiting the
+        // corners in order. Scoring that route against the closed-tour optimum must yield 1.0.
+        let problem = Problem {
+            name: "square".to_string(),
+            coordinates: vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)],
+            edge_weight_type: EdgeWeightType::Euc2d,
+        };
+        let instance = BenchmarkInstance::new(problem, 4.0);
+
+        let route = vec![0, 1, 2, 3];
+        let distance_matrix = instance.problem.distance_matrix();
+        let tour = Tour {
+            distance: get_route_distance(&distance_matrix, &route),
+            route,
+        };
+
+        assert_eq!(instance.closed_tour_length(&tour), 4.0);
+        assert_eq!(instance.approximation_ratio(&tour), 1.0);
+    }
+}
@@ -0,0 +1,149 @@
+//! # Travelling Salesman Problem Solvers
+//!
+//! This crate contains a collection of solvers for the
+//! [Travelling Salesman Problem](https://en.wikipedia.org/wiki/Travelling_salesman_problem):
+//! heuristic solvers, some built on top of the
+//! [metaheuristics](https://www.alfie.wtf/rustdoc/metaheuristics/) framework, and an exact
+//! solver for small instances.
+//!
+//!# Examples
+//!
+//!```
+//!extern crate time;
+//!extern crate travelling_salesman;
+//!
+//!fn main() {
+//!  let tour = travelling_salesman::simulated_annealing::solve(
+//!    &[
+//!       (27.0, 78.0),
+//!       (18.0, 24.0),
+//!       (48.0, 62.0),
+//!       (83.0, 77.0),
+//!       (55.0, 56.0),
+//!    ],
+//!    time::Duration::seconds(1),
+//!  );
+//!
+//!  println!("Tour distance: {}, route: {:?}", tour.distance, tour.route);
+//!}
+//!```
+extern crate metaheuristics;
+extern crate rand;
+extern crate time;
+
+pub mod exact;
+pub mod instances;
+pub mod local_search;
+pub mod simulated_annealing;
+pub mod tsplib;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Represents a tour through every city.
+///
+/// `distance` is the total length of the `route`, and `route` is the order in which the cities are
+/// visited, indexing into the `cities`/`distance_matrix` originally supplied to the solver.
+pub struct Tour {
+    pub distance: f64,
+    pub route: Vec<usize>,
+}
+
+/// A single candidate solution, as manipulated by the metaheuristics framework.
+#[derive(Clone)]
+pub struct Candidate {
+    pub route: Vec<usize>,
+}
+
+/// Holds the state a solver needs while searching: the precomputed `distance_matrix` and the random
+/// number generator used to generate and tweak candidates.
+pub struct TravellingSalesman<'a> {
+    pub distance_matrix: &'a Vec<Vec<f64>>,
+    pub rng: &'a mut dyn rand::RngCore,
+}
+
+impl<'a> metaheuristics::Metaheuristics<Candidate> for TravellingSalesman<'a> {
+    fn clone_candidate(&mut self, candidate: &Candidate) -> Candidate {
+        candidate.clone()
+    }
+
+    fn generate_candidate(&mut self) -> Candidate {
+        let mut route: Vec<usize> = (0..self.distance_matrix.len()).collect();
+        route.shuffle(self.rng);
+
+        Candidate { route }
+    }
+
+    fn rank_candidate(&mut self, candidate: &Candidate) -> f64 {
+        // metaheuristics maximises the rank, so a shorter route must rank higher.
+        0.0 - get_route_distance(self.distance_matrix, &candidate.route)
+    }
+
+    fn tweak_candidate(&mut self, candidate: &Candidate) -> Candidate {
+        let len = candidate.route.len();
+
+        if len <= 3 {
+            return candidate.clone();
+        }
+
+        // Reverse a random segment of the route (a 2-opt style move).
+        let start = self.rng.gen_range(0..len);
+        let span = self.rng.gen_range(1..len);
+
+        let mut route = candidate.route.clone();
+        let mut segment: Vec<usize> = (0..span).map(|offset| route[(start + offset) % len]).collect();
+        segment.reverse();
+
+        for (offset, &city) in segment.iter().enumerate() {
+            route[(start + offset) % len] = city;
+        }
+
+        Candidate { route }
+    }
+}
+
+/// Builds a Euclidean distance matrix from `(x, y)` coordinates.
+///
+/// `cities` is an array slice, containing `(x, y)` tuple coordinates for each city.
+///
+/// Returns a square matrix where entry `[i][j]` is the straight-line distance between city `i` and
+/// city `j`.
+pub fn get_distance_matrix(cities: &[(f64, f64)]) -> Vec<Vec<f64>> {
+    get_distance_matrix_with_metric(cities, |&(x1, y1), &(x2, y2)| {
+        ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt()
+    })
+}
+
+/// Builds a distance matrix from arbitrary items and a user-supplied distance function.
+///
+/// `items` is an array slice of anything the caller can measure a distance between — `(f64, f64)`
+/// coordinates, geographic points, graph nodes, and so on. `distance` returns the cost of
+/// travelling between two items; it need not be Euclidean.
+///
+/// Returns a square matrix where entry `[i][j]` is `distance(&items[i], &items[j])`, ready to be
+/// handed to any of the crate's solvers via `TravellingSalesman`.
+pub fn get_distance_matrix_with_metric<T, F>(items: &[T], distance: F) -> Vec<Vec<f64>>
+where
+    F: Fn(&T, &T) -> f64,
+{
+    items
+        .iter()
+        .map(|from| items.iter().map(|to| distance(from, to)).collect())
+        .collect()
+}
+
+/// Returns the total distance of `route` through the cities described by `distance_matrix`.
+pub fn get_route_distance(distance_matrix: &[Vec<f64>], route: &[usize]) -> f64 {
+    let mut route_iter = route.iter();
+
+    let mut current_city = match route_iter.next() {
+        None => return 0.0,
+        Some(&city) => city,
+    };
+
+    route_iter.fold(0.0, |total_distance, &next_city| {
+        let total_distance = total_distance + distance_matrix[current_city][next_city];
+        current_city = next_city;
+        total_distance
+    })
+}
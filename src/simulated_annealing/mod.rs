@@ -27,10 +27,15 @@
 //!```
 extern crate metaheuristics;
 
-use rand::thread_rng;
+use self::metaheuristics::Metaheuristics;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, RngCore, SeedableRng};
 use time::Duration;
 
-use super::{get_distance_matrix, get_route_distance, Tour, TravellingSalesman};
+use super::{
+    get_distance_matrix, get_distance_matrix_with_metric, get_route_distance, Tour,
+    TravellingSalesman,
+};
 
 /// Returns an approximate solution to the Travelling Salesman Problem using Simulated Annealing
 ///
@@ -68,19 +73,244 @@ use super::{get_distance_matrix, get_route_distance, Tour, TravellingSalesman};
 ///}
 ///```
 pub fn solve(cities: &[(f64, f64)], runtime: Duration) -> Tour {
+    let distance_matrix = get_distance_matrix(cities);
+    let params = AnnealingParams::auto(&distance_matrix);
+
+    solve_matrix_with_schedule(&distance_matrix, runtime, params)
+}
+
+/// The law by which the temperature is lowered after each batch of iterations.
+pub enum CoolingSchedule {
+    /// Multiplicative cooling: `T *= alpha` (typically `0.8..1.0`).
+    Geometric(f64),
+    /// Additive cooling: `T -= step`.
+    Linear(f64),
+}
+
+/// Controls the simulated-annealing cooling schedule and acceptance behaviour.
+///
+/// Use [`AnnealingParams::auto`] to derive sensible defaults from a distance matrix, then override
+/// individual fields as needed.
+pub struct AnnealingParams {
+    /// The starting temperature `T0`.
+    pub initial_temperature: f64,
+    /// How the temperature is lowered between batches.
+    pub cooling: CoolingSchedule,
+    /// The temperature floor; annealing stops once `T` drops to or below it.
+    pub minimum_temperature: f64,
+    /// How many candidate moves are attempted at each temperature.
+    pub iterations_per_temperature: usize,
+    /// The Boltzmann constant `k` in the acceptance rule.
+    pub boltzmann_k: f64,
+}
+
+impl AnnealingParams {
+    /// Derives defaults from a `distance_matrix`, seeding `T0` from its mean edge length.
+    pub fn auto(distance_matrix: &Vec<Vec<f64>>) -> AnnealingParams {
+        AnnealingParams {
+            initial_temperature: mean_edge_length(distance_matrix),
+            cooling: CoolingSchedule::Geometric(0.95),
+            minimum_temperature: 1e-3,
+            iterations_per_temperature: distance_matrix.len().max(1) * 100,
+            boltzmann_k: 1.0,
+        }
+    }
+}
+
+/// Returns an approximate solution using simulated annealing with an explicit schedule.
+///
+/// Acceptance follows the Boltzmann rule: a shorter candidate route is always accepted, otherwise
+/// the candidate is accepted with probability `exp(-(E_new - E_old) / (k * T))`. The temperature
+/// `T` is decremented on the chosen [`CoolingSchedule`] after each batch of
+/// `iterations_per_temperature` moves, until the `runtime` elapses or `T` reaches
+/// `minimum_temperature`.
+///
+///# Parameters and Return Type
+///
+/// `cities` is an array slice, containing `(x,y)` tuple coordinates for each city.
+///
+/// `runtime` is a `time::Duration`, specifying how long to spend searching for a solution.
+///
+/// `params` is an [`AnnealingParams`] controlling the schedule and acceptance; see
+/// [`AnnealingParams::auto`] for defaults.
+///
+/// Returns a `travelling_salesman::Tour` struct, representing the approximate solution found.
+pub fn solve_with_schedule(
+    cities: &[(f64, f64)],
+    runtime: Duration,
+    params: AnnealingParams,
+) -> Tour {
+    solve_matrix_with_schedule(&get_distance_matrix(cities), runtime, params)
+}
+
+/// Returns an approximate solution driven by a caller-supplied random number generator.
+///
+/// Because every source of randomness flows through `rng`, fixing it makes the search
+/// reproducible — the same `rng` state yields identical tours across runs — which is what
+/// regression tests and published benchmarks need.
+///
+/// The annealing loop is also wall-clock bounded: it stops at whichever comes first, the
+/// temperature reaching `minimum_temperature` or `runtime` elapsing. Reproducibility therefore
+/// only holds when the schedule reaches the temperature floor within `runtime`; if `runtime` cuts
+/// the loop short (large `n` or a short budget), the number of completed steps — and thus the
+/// result — can still vary run to run even with a fixed `rng`. See [`solve_seeded`] for the common
+/// case of seeding from a `u64`.
+///
+///# Parameters and Return Type
+///
+/// `cities` is an array slice, containing `(x,y)` tuple coordinates for each city.
+///
+/// `runtime` is a `time::Duration`, specifying how long to spend searching for a solution.
+///
+/// `rng` is the random number generator to draw from.
+///
+/// Returns a `travelling_salesman::Tour` struct, representing the approximate solution found.
+pub fn solve_with_rng(cities: &[(f64, f64)], runtime: Duration, rng: &mut impl Rng) -> Tour {
+    let distance_matrix = get_distance_matrix(cities);
+    let params = AnnealingParams::auto(&distance_matrix);
+
+    solve_matrix_with_schedule_rng(&distance_matrix, runtime, params, rng)
+}
+
+/// Returns an approximate solution that is reproducible for a given `seed`.
+///
+/// Builds a `StdRng` from `seed` and threads it through the solver, so the same `seed` yields the
+/// same tour — subject to the same wall-clock caveat as [`solve_with_rng`]: reproducibility holds
+/// only when the cooling schedule reaches `minimum_temperature` before `runtime` elapses.
+pub fn solve_seeded(cities: &[(f64, f64)], runtime: Duration, seed: u64) -> Tour {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    solve_with_rng(cities, runtime, &mut rng)
+}
+
+/// Runs the scheduled annealing loop directly against a precomputed `distance_matrix`, using the
+/// thread-local random number generator.
+pub fn solve_matrix_with_schedule(
+    distance_matrix: &Vec<Vec<f64>>,
+    runtime: Duration,
+    params: AnnealingParams,
+) -> Tour {
+    solve_matrix_with_schedule_rng(distance_matrix, runtime, params, &mut thread_rng())
+}
+
+/// Runs the scheduled annealing loop against a precomputed `distance_matrix` with an explicit
+/// random number generator, so the search is reproducible.
+pub fn solve_matrix_with_schedule_rng(
+    distance_matrix: &Vec<Vec<f64>>,
+    runtime: Duration,
+    params: AnnealingParams,
+    rng: &mut dyn RngCore,
+) -> Tour {
     let mut tsp = TravellingSalesman {
-        distance_matrix: &get_distance_matrix(cities),
-        rng: &mut thread_rng(),
+        distance_matrix,
+        rng,
     };
 
-    let best_candidate = metaheuristics::simulated_annealing::solve(&mut tsp, runtime);
+    let deadline = time::precise_time_s() + runtime.num_milliseconds() as f64 / 1000.0;
+
+    let mut current = tsp.generate_candidate();
+    let mut current_energy = get_route_distance(distance_matrix, &current.route);
+
+    let mut best = tsp.clone_candidate(&current);
+    let mut best_energy = current_energy;
+
+    let mut temperature = params.initial_temperature;
+
+    while temperature > params.minimum_temperature && time::precise_time_s() < deadline {
+        for _ in 0..params.iterations_per_temperature {
+            let candidate = tsp.tweak_candidate(&current);
+            let candidate_energy = get_route_distance(distance_matrix, &candidate.route);
+            let delta = candidate_energy - current_energy;
+
+            let accept = delta < 0.0
+                || tsp.rng.gen::<f64>() < (-delta / (params.boltzmann_k * temperature)).exp();
+
+            if accept {
+                current = candidate;
+                current_energy = candidate_energy;
+
+                if current_energy < best_energy {
+                    best = tsp.clone_candidate(&current);
+                    best_energy = current_energy;
+                }
+            }
+        }
+
+        temperature = match params.cooling {
+            CoolingSchedule::Geometric(alpha) => temperature * alpha,
+            CoolingSchedule::Linear(step) => temperature - step,
+        };
+    }
 
     Tour {
-        distance: get_route_distance(tsp.distance_matrix, &best_candidate.route),
-        route: best_candidate.route,
+        distance: best_energy,
+        route: best.route,
     }
 }
 
+/// Returns the mean of the off-diagonal entries of `distance_matrix`.
+fn mean_edge_length(distance_matrix: &Vec<Vec<f64>>) -> f64 {
+    let len = distance_matrix.len();
+
+    if len < 2 {
+        return 1.0;
+    }
+
+    let total: f64 = distance_matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(move |&(j, _)| i != j)
+                .map(|(_, &distance)| distance)
+        })
+        .sum();
+
+    total / (len * (len - 1)) as f64
+}
+
+/// Returns an approximate solution for items in an arbitrary distance space.
+///
+/// Unlike [`solve`], which hard-codes the Euclidean distance between `(x, y)` coordinates, this
+/// entry point accepts any `items` together with a `distance` closure, letting callers solve over
+/// geographic (haversine), Manhattan, road-network, or otherwise non-planar cost spaces.
+///
+///# Parameters and Return Type
+///
+/// `items` is an array slice of whatever the caller can measure a distance between.
+///
+/// `distance` returns the cost of travelling between two items.
+///
+/// `runtime` is a `time::Duration`, specifying how long to spend searching for a solution.
+///
+/// Returns a `travelling_salesman::Tour` struct, representing the approximate solution found. The
+/// `route` indexes back into `items`.
+///
+///# Examples
+///
+///```
+///extern crate time;
+///extern crate travelling_salesman;
+///
+///fn main() {
+///  // Manhattan distance over a grid.
+///  let tour = travelling_salesman::simulated_annealing::solve_with_metric(
+///    &[(27.0, 78.0), (18.0, 24.0), (48.0, 62.0), (83.0, 77.0), (55.0, 56.0)],
+///    |&(x1, y1): &(f64, f64), &(x2, y2): &(f64, f64)| (x2 - x1).abs() + (y2 - y1).abs(),
+///    time::Duration::seconds(1),
+///  );
+///
+///  println!("Tour distance: {}, route: {:?}", tour.distance, tour.route);
+///}
+///```
+pub fn solve_with_metric<T, F>(items: &[T], distance: F, runtime: Duration) -> Tour
+where
+    F: Fn(&T, &T) -> f64,
+{
+    solve_matrix(&get_distance_matrix_with_metric(items, distance), runtime)
+}
+
 pub fn solve_matrix(distance_matrix: &Vec<Vec<f64>>, runtime: Duration) -> Tour {
     let mut tsp = TravellingSalesman {
         distance_matrix,
@@ -94,3 +324,27 @@ pub fn solve_matrix(distance_matrix: &Vec<Vec<f64>>, runtime: Duration) -> Tour
         route: best_candidate.route,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_seeded_is_reproducible_for_a_fixed_seed() {
+        let cities = [
+            (27.0, 78.0),
+            (18.0, 24.0),
+            (48.0, 62.0),
+            (83.0, 77.0),
+            (55.0, 56.0),
+            (14.0, 9.0),
+        ];
+
+        // A generous runtime over a tiny instance lets the schedule reach the temperature floor
+        // before the deadline, which is the condition under which seeding is reproducible.
+        let first = solve_seeded(&cities, Duration::seconds(1), 42);
+        let second = solve_seeded(&cities, Duration::seconds(1), 42);
+
+        assert_eq!(first.route, second.route);
+    }
+}
@@ -0,0 +1,204 @@
+//! Parse [TSPLIB](http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/)-format `.tsp` files.
+//!
+//! Supports node-coordinate problems whose `EDGE_WEIGHT_TYPE` is `EUC_2D`, `ATT`, or `GEO`. The
+//! coordinates are read out of the `NODE_COORD_SECTION` into a `Vec<(f64, f64)>`, and the edge
+//! weight type selects the metric used to turn them into a distance matrix the crate's solvers can
+//! consume.
+//!
+//!# Examples
+//!
+//!```
+//!extern crate time;
+//!extern crate travelling_salesman;
+//!
+//!fn main() {
+//!  let problem = travelling_salesman::tsplib::parse(
+//!    "NAME: square\n\
+//!     TYPE: TSP\n\
+//!     DIMENSION: 4\n\
+//!     EDGE_WEIGHT_TYPE: EUC_2D\n\
+//!     NODE_COORD_SECTION\n\
+//!     1 0 0\n\
+//!     2 0 1\n\
+//!     3 1 1\n\
+//!     4 1 0\n\
+//!     EOF\n",
+//!  ).unwrap();
+//!
+//!  let tour = travelling_salesman::simulated_annealing::solve_matrix(
+//!    &problem.distance_matrix(),
+//!    time::Duration::seconds(1),
+//!  );
+//!
+//!  println!("Tour distance: {}, route: {:?}", tour.distance, tour.route);
+//!}
+//!```
+use std::error::Error;
+use std::fmt;
+
+use super::get_distance_matrix_with_metric;
+
+/// The edge-weight types this loader understands.
+pub enum EdgeWeightType {
+    /// Rounded planar Euclidean distance.
+    Euc2d,
+    /// The `ATT` pseudo-Euclidean distance.
+    Att,
+    /// Great-circle distance over geographic coordinates.
+    Geo,
+}
+
+impl EdgeWeightType {
+    /// Returns the distance between two coordinates under this edge-weight type.
+    pub fn distance(&self, &(x1, y1): &(f64, f64), &(x2, y2): &(f64, f64)) -> f64 {
+        let dx = x1 - x2;
+        let dy = y1 - y2;
+
+        match *self {
+            EdgeWeightType::Euc2d => (dx * dx + dy * dy).sqrt().round(),
+            // The ATT metric rounds sqrt((dx² + dy²) / 10) up, matching the published optima.
+            EdgeWeightType::Att => ((dx * dx + dy * dy) / 10.0).sqrt().ceil(),
+            EdgeWeightType::Geo => geo_distance((x1, y1), (x2, y2)),
+        }
+    }
+}
+
+/// A parsed problem: its coordinates plus the metric that measures distances between them.
+pub struct Problem {
+    pub name: String,
+    pub coordinates: Vec<(f64, f64)>,
+    pub edge_weight_type: EdgeWeightType,
+}
+
+impl Problem {
+    /// Builds the distance matrix for this problem, ready to hand to any of the crate's solvers.
+    pub fn distance_matrix(&self) -> Vec<Vec<f64>> {
+        get_distance_matrix_with_metric(&self.coordinates, |from, to| {
+            self.edge_weight_type.distance(from, to)
+        })
+    }
+}
+
+/// The ways parsing a TSPLIB file can fail.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The `EDGE_WEIGHT_TYPE` was missing or not one of the supported types.
+    UnsupportedEdgeWeightType(String),
+    /// The `NODE_COORD_SECTION` was absent.
+    MissingCoordinates,
+    /// A coordinate line could not be read as `index x y`.
+    MalformedCoordinate(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::UnsupportedEdgeWeightType(ref found) => {
+                write!(f, "unsupported EDGE_WEIGHT_TYPE: {}", found)
+            }
+            ParseError::MissingCoordinates => write!(f, "missing NODE_COORD_SECTION"),
+            ParseError::MalformedCoordinate(ref line) => {
+                write!(f, "malformed coordinate line: {}", line)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses the contents of a TSPLIB `.tsp` file into a [`Problem`].
+pub fn parse(input: &str) -> Result<Problem, ParseError> {
+    let mut name = String::new();
+    let mut edge_weight_type = None;
+    let mut coordinates = Vec::new();
+    let mut in_coord_section = false;
+
+    for line in input.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if in_coord_section {
+            if line == "EOF" {
+                break;
+            }
+
+            let mut fields = line.split_whitespace();
+            let x = fields.nth(1).and_then(|field| field.parse().ok());
+            let y = fields.next().and_then(|field| field.parse().ok());
+
+            match (x, y) {
+                (Some(x), Some(y)) => coordinates.push((x, y)),
+                _ => return Err(ParseError::MalformedCoordinate(line.to_string())),
+            }
+
+            continue;
+        }
+
+        if line == "NODE_COORD_SECTION" {
+            in_coord_section = true;
+            continue;
+        }
+
+        let (keyword, value) = match line.split_once(':') {
+            Some((keyword, value)) => (keyword.trim(), value.trim()),
+            None => continue,
+        };
+
+        match keyword {
+            "NAME" => name = value.to_string(),
+            "EDGE_WEIGHT_TYPE" => {
+                edge_weight_type = Some(match value {
+                    "EUC_2D" => EdgeWeightType::Euc2d,
+                    "ATT" => EdgeWeightType::Att,
+                    "GEO" => EdgeWeightType::Geo,
+                    other => {
+                        return Err(ParseError::UnsupportedEdgeWeightType(other.to_string()))
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    if coordinates.is_empty() {
+        return Err(ParseError::MissingCoordinates);
+    }
+
+    let edge_weight_type = edge_weight_type
+        .ok_or_else(|| ParseError::UnsupportedEdgeWeightType(String::new()))?;
+
+    Ok(Problem {
+        name,
+        coordinates,
+        edge_weight_type,
+    })
+}
+
+/// Great-circle distance between two geographic coordinates, per the TSPLIB `GEO` definition.
+fn geo_distance((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+    const RRR: f64 = 6378.388;
+
+    let lat1 = to_radians(lat1);
+    let lon1 = to_radians(lon1);
+    let lat2 = to_radians(lat2);
+    let lon2 = to_radians(lon2);
+
+    let q1 = (lon1 - lon2).cos();
+    let q2 = (lat1 - lat2).cos();
+    let q3 = (lat1 + lat2).cos();
+
+    (RRR * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0).floor()
+}
+
+/// Converts a TSPLIB `DDD.MM` coordinate into radians.
+fn to_radians(coordinate: f64) -> f64 {
+    const PI: f64 = 3.141592;
+
+    let degrees = coordinate.trunc();
+    let minutes = coordinate - degrees;
+
+    PI * (degrees + 5.0 * minutes / 3.0) / 180.0
+}